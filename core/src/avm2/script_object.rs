@@ -1,7 +1,7 @@
 //! Default AVM2 object impl
 
 use crate::avm2::function::Executable;
-use crate::avm2::names::QName;
+use crate::avm2::names::{Namespace, QName};
 use crate::avm2::object::{Object, ObjectPtr, TObject};
 use crate::avm2::property::Property;
 use crate::avm2::return_value::ReturnValue;
@@ -28,6 +28,13 @@ pub struct ScriptObjectData<'gc> {
 
     /// Implicit prototype (or declared base class) of this script object.
     proto: Option<Object<'gc>>,
+
+    /// Whether this object rejects writes to undeclared property names
+    /// (i.e. is an instance of a non-`dynamic` AS3 class).
+    sealed: bool,
+
+    /// Insertion order of this object's dynamic (enumerable) properties.
+    enumerants: Vec<QName>,
 }
 
 impl<'gc> TObject<'gc> for ScriptObject<'gc> {
@@ -69,6 +76,37 @@ impl<'gc> TObject<'gc> for ScriptObject<'gc> {
         self.0.read().has_property(name)
     }
 
+    fn has_own_property(self, name: &QName) -> bool {
+        self.0.read().has_own_property(name)
+    }
+
+    fn has_virtual_property(self, name: &QName) -> bool {
+        self.0.read().has_virtual_property(name)
+    }
+
+    fn get_property_with_receiver(
+        self,
+        name: &QName,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        receiver: Object<'gc>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        self.0.read().get_property(name, avm, context, receiver)
+    }
+
+    fn set_property_with_receiver(
+        self,
+        name: &QName,
+        value: Value<'gc>,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        receiver: Object<'gc>,
+    ) -> Result<(), Error> {
+        self.0
+            .write(context.gc_context)
+            .set_property(name, value, avm, context, receiver)
+    }
+
     fn proto(&self) -> Option<Object<'gc>> {
         self.0.read().proto
     }
@@ -117,6 +155,80 @@ impl<'gc> TObject<'gc> for ScriptObject<'gc> {
     ) -> Result<(), Error> {
         self.0.write(mc).install_dynamic_property(name, value)
     }
+
+    fn delete_property(&mut self, mc: MutationContext<'gc, '_>, name: &QName) -> bool {
+        self.0.write(mc).delete_property(name)
+    }
+
+    fn get_enumerant_name(&self, index: u32) -> Option<QName> {
+        self.0.read().get_enumerant_name(index)
+    }
+
+    fn get_enumerant_name_excluding(&self, index: u32, shadowed: &[QName]) -> Option<QName> {
+        self.0.read().get_enumerant_name_excluding(index, shadowed)
+    }
+
+    fn get_enumerant_value(
+        self,
+        index: u32,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        self.0
+            .read()
+            .get_enumerant_value(index, avm, context, self.into())
+    }
+
+    fn property_is_enumerable(&self, name: &QName) -> bool {
+        self.0.read().property_is_enumerable(name)
+    }
+
+    fn get_property_multiname(
+        self,
+        local_name: &str,
+        ns_set: &[Namespace],
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        self.0
+            .read()
+            .get_property_multiname(local_name, ns_set, avm, context, self.into())
+    }
+
+    fn get_property_multiname_with_receiver(
+        self,
+        local_name: &str,
+        ns_set: &[Namespace],
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        receiver: Object<'gc>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        self.0
+            .read()
+            .get_property_multiname(local_name, ns_set, avm, context, receiver)
+    }
+
+    fn set_property_multiname(
+        self,
+        local_name: &str,
+        ns_set: &[Namespace],
+        value: Value<'gc>,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> Result<(), Error> {
+        self.0.write(context.gc_context).set_property_multiname(
+            local_name,
+            ns_set,
+            value,
+            avm,
+            context,
+            self.into(),
+        )
+    }
+
+    fn has_property_multiname(self, local_name: &str, ns_set: &[Namespace]) -> bool {
+        self.0.read().has_property_multiname(local_name, ns_set)
+    }
 }
 
 impl<'gc> ScriptObject<'gc> {
@@ -136,6 +248,32 @@ impl<'gc> ScriptObject<'gc> {
         ))
         .into()
     }
+
+    /// Construct a sealed (non-`dynamic`) object with no base class.
+    pub fn sealed_object(mc: MutationContext<'gc, '_>) -> Object<'gc> {
+        ScriptObject(GcCell::allocate(mc, ScriptObjectData::sealed_new(None))).into()
+    }
+
+    /// Construct a sealed (non-`dynamic`) object with the given base class.
+    pub fn class_object(mc: MutationContext<'gc, '_>, proto: Object<'gc>) -> Object<'gc> {
+        ScriptObject(GcCell::allocate(
+            mc,
+            ScriptObjectData::sealed_new(Some(proto)),
+        ))
+        .into()
+    }
+}
+
+/// How a write to a name with no existing own property should be carried
+/// out; see `classify_expando_write`.
+#[derive(Debug)]
+enum PropertyWrite<'gc> {
+    /// Forward the write to an inherited accessor on this prototype.
+    ThroughProto(Object<'gc>),
+    /// Reject the write: this object is sealed and does not own the name.
+    RejectSealed,
+    /// Create a brand new own dynamic property.
+    CreateOwn,
 }
 
 impl<'gc> ScriptObjectData<'gc> {
@@ -144,9 +282,25 @@ impl<'gc> ScriptObjectData<'gc> {
             values: HashMap::new(),
             slots: Vec::new(),
             proto,
+            sealed: false,
+            enumerants: Vec::new(),
+        }
+    }
+
+    /// Construct a sealed (non-`dynamic`) object.
+    pub fn sealed_new(proto: Option<Object<'gc>>) -> Self {
+        ScriptObjectData {
+            values: HashMap::new(),
+            slots: Vec::new(),
+            proto,
+            sealed: true,
+            enumerants: Vec::new(),
         }
     }
 
+    /// Retrieve a property by name, walking the prototype chain if it isn't
+    /// defined on this object. `this` is the original receiver and is
+    /// preserved across hops so inherited getters see the right object.
     pub fn get_property(
         &self,
         name: &QName,
@@ -154,10 +308,10 @@ impl<'gc> ScriptObjectData<'gc> {
         context: &mut UpdateContext<'_, 'gc, '_>,
         this: Object<'gc>,
     ) -> Result<ReturnValue<'gc>, Error> {
-        let prop = self.values.get(name);
-
-        if let Some(prop) = prop {
+        if let Some(prop) = self.values.get(name) {
             prop.get(avm, context, this)
+        } else if let Some(proto) = self.proto {
+            proto.get_property_with_receiver(name, avm, context, this)
         } else {
             Ok(Value::Undefined.into())
         }
@@ -172,14 +326,51 @@ impl<'gc> ScriptObjectData<'gc> {
         this: Object<'gc>,
     ) -> Result<(), Error> {
         if let Some(prop) = self.values.get_mut(name) {
-            prop.set(avm, context, this, value)?;
-        } else {
-            //TODO: Not all classes are dynamic like this
-            self.values
-                .insert(name.clone(), Property::new_dynamic_property(value));
+            return prop.set(avm, context, this, value);
         }
 
-        Ok(())
+        match self.classify_expando_write(name) {
+            PropertyWrite::ThroughProto(proto) => {
+                proto.set_property_with_receiver(name, value, avm, context, this)
+            }
+            PropertyWrite::RejectSealed => Err(format!(
+                "Cannot create property {} on sealed object",
+                name.local_name()
+            )
+            .into()),
+            PropertyWrite::CreateOwn => {
+                self.values
+                    .insert(name.clone(), Property::new_dynamic_property(value));
+                self.enumerants.push(name.clone());
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Decide how a write to a name with no existing own property should be
+    /// handled, without actually performing it. Pulled out of `set_property`
+    /// so this decision (in particular, the sealed/expando logic) can be
+    /// unit tested without an `Avm2`/`UpdateContext`. Only called once an
+    /// own-property write has already been ruled out.
+    fn classify_expando_write(&self, name: &QName) -> PropertyWrite<'gc> {
+        // Only accessor properties write through the prototype chain;
+        // inherited data properties are shadowed by a new own property,
+        // same as plain JS/AS3 prototypal assignment.
+        if let Some(proto) = self.proto {
+            if proto.has_virtual_property(name) {
+                return PropertyWrite::ThroughProto(proto);
+            }
+        }
+
+        // This name is not an own property, so a sealed object must refuse
+        // to create it as a new expando regardless of whether it exists on
+        // the prototype chain.
+        if self.sealed {
+            return PropertyWrite::RejectSealed;
+        }
+
+        PropertyWrite::CreateOwn
     }
 
     pub fn get_slot(&self, id: u32) -> Result<Value<'gc>, Error> {
@@ -205,14 +396,128 @@ impl<'gc> ScriptObjectData<'gc> {
         }
     }
 
+    /// Check if a property is defined on this object or its prototype chain.
     pub fn has_property(&self, name: &QName) -> bool {
-        self.values.get(name).is_some()
+        if self.values.contains_key(name) {
+            true
+        } else if let Some(proto) = self.proto {
+            proto.has_property(name)
+        } else {
+            false
+        }
+    }
+
+    /// Check if a property is defined directly on this object (AS3 `hasOwnProperty`).
+    pub fn has_own_property(&self, name: &QName) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Check if `name` resolves (on this object or its prototype chain) to
+    /// an accessor property, as opposed to a plain data property.
+    pub fn has_virtual_property(&self, name: &QName) -> bool {
+        self.values.get(name).map_or(false, |prop| prop.is_virtual())
+            || self
+                .proto
+                .map_or(false, |proto| proto.has_virtual_property(name))
+    }
+
+    /// Resolve a multiname (a local name plus candidate namespaces, tried in
+    /// order) against this object's own properties. `Namespace::any()`
+    /// matches the first property with a matching local name regardless of
+    /// namespace.
+    pub fn resolve_multiname(&self, local_name: &str, ns_set: &[Namespace]) -> Option<QName> {
+        for ns in ns_set {
+            if ns.is_any() {
+                if let Some(name) = self
+                    .enumerants
+                    .iter()
+                    .find(|name| name.local_name() == local_name)
+                {
+                    return Some(name.clone());
+                }
+
+                if let Some(name) = self
+                    .values
+                    .keys()
+                    .find(|name| name.local_name() == local_name)
+                {
+                    return Some(name.clone());
+                }
+
+                continue;
+            }
+
+            let qname = QName::new(ns.clone(), local_name);
+            if self.values.contains_key(&qname) {
+                return Some(qname);
+            }
+        }
+
+        None
+    }
+
+    /// Multiname-accepting variant of `get_property`. Resolves `local_name`
+    /// against `ns_set` on this object, falling back to the prototype chain
+    /// (with the original receiver preserved) if no namespace matches here.
+    pub fn get_property_multiname(
+        &self,
+        local_name: &str,
+        ns_set: &[Namespace],
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        this: Object<'gc>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        if let Some(name) = self.resolve_multiname(local_name, ns_set) {
+            self.get_property(&name, avm, context, this)
+        } else if let Some(proto) = self.proto {
+            proto.get_property_multiname_with_receiver(local_name, ns_set, avm, context, this)
+        } else {
+            Ok(Value::Undefined.into())
+        }
+    }
+
+    /// Multiname-accepting variant of `set_property`.
+    pub fn set_property_multiname(
+        &mut self,
+        local_name: &str,
+        ns_set: &[Namespace],
+        value: Value<'gc>,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        this: Object<'gc>,
+    ) -> Result<(), Error> {
+        let name = self.resolve_multiname(local_name, ns_set).unwrap_or_else(|| {
+            let ns = ns_set
+                .iter()
+                .find(|ns| !ns.is_any())
+                .cloned()
+                .unwrap_or_else(Namespace::public);
+            QName::new(ns, local_name)
+        });
+
+        self.set_property(&name, value, avm, context, this)
+    }
+
+    /// Multiname-accepting variant of `has_property`.
+    pub fn has_property_multiname(&self, local_name: &str, ns_set: &[Namespace]) -> bool {
+        if self.resolve_multiname(local_name, ns_set).is_some() {
+            true
+        } else if let Some(proto) = self.proto {
+            proto.has_property_multiname(local_name, ns_set)
+        } else {
+            false
+        }
     }
 
     pub fn proto(&self) -> Option<Object<'gc>> {
         self.proto
     }
 
+    /// Check if this object rejects writes to undeclared property names.
+    pub fn is_sealed(&self) -> bool {
+        self.sealed
+    }
+
     /// Install a method into the object.
     pub fn install_method(&mut self, name: QName, function: Object<'gc>) {
         self.values.insert(name, Property::new_method(function));
@@ -255,9 +560,312 @@ impl<'gc> ScriptObjectData<'gc> {
         name: QName,
         value: Value<'gc>,
     ) -> Result<(), Error> {
+        if !self.values.contains_key(&name) {
+            self.enumerants.push(name.clone());
+        }
+
         self.values
             .insert(name, Property::new_dynamic_property(value));
 
         Ok(())
     }
+
+    /// Delete a property from this object.
+    ///
+    /// Only dynamic (expando) properties can be deleted; fixed traits and
+    /// methods refuse deletion, matching AS3 `delete` semantics. Deleting a
+    /// name that isn't present at all is a no-op that still reports success.
+    pub fn delete_property(&mut self, name: &QName) -> bool {
+        let deletable = self
+            .values
+            .get(name)
+            .map(|prop| prop.is_dynamic())
+            .unwrap_or(true);
+
+        if deletable {
+            self.values.remove(name);
+            self.enumerants.retain(|enumerant| enumerant != name);
+        }
+
+        deletable
+    }
+
+    /// Look up the name of the enumerable (dynamic, non-trait) property at
+    /// `index`, continuing into the prototype chain once exhausted here.
+    pub fn get_enumerant_name(&self, index: u32) -> Option<QName> {
+        self.get_enumerant_name_excluding(index, &[])
+    }
+
+    /// `get_enumerant_name`, but skipping names already shadowed lower in
+    /// the prototype chain.
+    fn get_enumerant_name_excluding(&self, index: u32, shadowed: &[QName]) -> Option<QName> {
+        let mut remaining = index;
+
+        for name in &self.enumerants {
+            if shadowed.contains(name) {
+                continue;
+            }
+
+            if remaining == 0 {
+                return Some(name.clone());
+            }
+
+            remaining -= 1;
+        }
+
+        let proto = self.proto?;
+        let mut shadowed_by_self = shadowed.to_vec();
+        shadowed_by_self.extend(self.values.keys().cloned());
+        proto.get_enumerant_name_excluding(remaining, &shadowed_by_self)
+    }
+
+    /// Look up the value of the enumerable property at `index`.
+    pub fn get_enumerant_value(
+        &self,
+        index: u32,
+        avm: &mut Avm2<'gc>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        this: Object<'gc>,
+    ) -> Result<ReturnValue<'gc>, Error> {
+        match self.get_enumerant_name(index) {
+            Some(name) => self.get_property(&name, avm, context, this),
+            None => Ok(Value::Undefined.into()),
+        }
+    }
+
+    /// Check if a given property is enumerable, i.e. whether it is a
+    /// dynamic property that enumeration will visit.
+    pub fn property_is_enumerable(&self, name: &QName) -> bool {
+        self.enumerants.contains(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_arena::ArenaParameters;
+
+    gc_arena::make_arena!(TestArena, ScriptObject);
+
+    fn with_mc<F>(f: F)
+    where
+        F: for<'gc> FnOnce(MutationContext<'gc, '_>),
+    {
+        let mut arena = TestArena::new(ArenaParameters::default(), |mc| {
+            ScriptObject(GcCell::allocate(mc, ScriptObjectData::base_new(None)))
+        });
+        arena.mutate(|mc, _root| f(mc));
+    }
+
+    fn qname(local: &str) -> QName {
+        QName::new(Namespace::public(), local)
+    }
+
+    #[test]
+    fn prototype_chain_is_consulted_for_has_property() {
+        with_mc(|mc| {
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data
+                .install_dynamic_property(qname("inherited"), Value::Undefined)
+                .unwrap();
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let child = ScriptObjectData::base_new(Some(proto));
+
+            assert!(child.has_property(&qname("inherited")));
+            assert!(!child.has_own_property(&qname("inherited")));
+            assert!(!child.has_property(&qname("missing")));
+        });
+    }
+
+    #[test]
+    fn has_virtual_property_is_false_for_inherited_data_properties() {
+        with_mc(|mc| {
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data
+                .install_dynamic_property(qname("data"), Value::Undefined)
+                .unwrap();
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let child = ScriptObjectData::base_new(Some(proto));
+
+            assert!(!child.has_virtual_property(&qname("data")));
+        });
+    }
+
+    #[test]
+    fn sealed_instances_see_inherited_members_without_owning_them() {
+        with_mc(|mc| {
+            let function = Object::ScriptObject(ScriptObject(GcCell::allocate(
+                mc,
+                ScriptObjectData::base_new(None),
+            )));
+
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data.install_method(qname("method"), function);
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let child = ScriptObjectData::sealed_new(Some(proto));
+
+            assert!(child.is_sealed());
+            assert!(child.has_property(&qname("method")));
+            assert!(!child.has_own_property(&qname("method")));
+            assert!(!child.has_property(&qname("missing")));
+        });
+    }
+
+    // `set_property`'s sealed-write guard (`if self.sealed { .. }`, reached
+    // only once an own-property write and a virtual-property proto
+    // write-through have both been ruled out) can't be exercised directly
+    // here without an `Avm2`/`UpdateContext` fixture, so these tests drive
+    // `classify_expando_write` instead, which makes that same decision.
+    #[test]
+    fn sealed_object_rejects_write_to_an_entirely_unknown_name() {
+        let child = ScriptObjectData::sealed_new(None);
+
+        assert!(matches!(
+            child.classify_expando_write(&qname("missing")),
+            PropertyWrite::RejectSealed
+        ));
+    }
+
+    #[test]
+    fn sealed_object_rejects_write_shadowing_an_inherited_data_property() {
+        with_mc(|mc| {
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data
+                .install_dynamic_property(qname("data"), Value::Undefined)
+                .unwrap();
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let child = ScriptObjectData::sealed_new(Some(proto));
+
+            // `data` resolves via the prototype chain, but it is not an own
+            // property, so the write must still be rejected rather than
+            // materializing a new own enumerable expando.
+            assert!(child.has_property(&qname("data")));
+            assert!(matches!(
+                child.classify_expando_write(&qname("data")),
+                PropertyWrite::RejectSealed
+            ));
+        });
+    }
+
+    #[test]
+    fn dynamic_object_creates_an_own_property_for_an_unknown_name() {
+        let child = ScriptObjectData::base_new(None);
+
+        assert!(matches!(
+            child.classify_expando_write(&qname("missing")),
+            PropertyWrite::CreateOwn
+        ));
+    }
+
+    #[test]
+    fn install_dynamic_property_does_not_duplicate_enumerants() {
+        let mut data = ScriptObjectData::base_new(None);
+        data.install_dynamic_property(qname("a"), Value::Undefined)
+            .unwrap();
+        data.install_dynamic_property(qname("a"), Value::Undefined)
+            .unwrap();
+
+        assert_eq!(data.get_enumerant_name(0), Some(qname("a")));
+        assert_eq!(data.get_enumerant_name(1), None);
+    }
+
+    #[test]
+    fn enumeration_is_ordered_and_skips_prototype_duplicates() {
+        with_mc(|mc| {
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data
+                .install_dynamic_property(qname("a"), Value::Undefined)
+                .unwrap();
+            proto_data
+                .install_dynamic_property(qname("b"), Value::Undefined)
+                .unwrap();
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let mut child = ScriptObjectData::base_new(Some(proto));
+            child
+                .install_dynamic_property(qname("b"), Value::Undefined)
+                .unwrap();
+            child
+                .install_dynamic_property(qname("c"), Value::Undefined)
+                .unwrap();
+
+            assert_eq!(child.get_enumerant_name(0), Some(qname("b")));
+            assert_eq!(child.get_enumerant_name(1), Some(qname("c")));
+            assert_eq!(child.get_enumerant_name(2), Some(qname("a")));
+            assert_eq!(child.get_enumerant_name(3), None);
+        });
+    }
+
+    #[test]
+    fn enumeration_skips_inherited_names_shadowed_by_an_own_method() {
+        with_mc(|mc| {
+            let function = Object::ScriptObject(ScriptObject(GcCell::allocate(
+                mc,
+                ScriptObjectData::base_new(None),
+            )));
+
+            let mut proto_data = ScriptObjectData::base_new(None);
+            proto_data
+                .install_dynamic_property(qname("foo"), Value::Undefined)
+                .unwrap();
+            let proto = Object::ScriptObject(ScriptObject(GcCell::allocate(mc, proto_data)));
+
+            let mut child = ScriptObjectData::base_new(Some(proto));
+            child.install_method(qname("foo"), function);
+
+            // `foo` is an own (non-enumerable) method, so it must not cause
+            // the inherited dynamic `foo` to be enumerated.
+            assert_eq!(child.get_enumerant_name(0), None);
+        });
+    }
+
+    #[test]
+    fn delete_property_removes_dynamic_but_not_fixed_members() {
+        with_mc(|mc| {
+            let function = Object::ScriptObject(ScriptObject(GcCell::allocate(
+                mc,
+                ScriptObjectData::base_new(None),
+            )));
+
+            let mut data = ScriptObjectData::base_new(None);
+            data.install_dynamic_property(qname("expando"), Value::Undefined)
+                .unwrap();
+            data.install_method(qname("method"), function);
+
+            assert!(data.delete_property(&qname("expando")));
+            assert!(!data.has_own_property(&qname("expando")));
+
+            assert!(!data.delete_property(&qname("method")));
+            assert!(data.has_own_property(&qname("method")));
+        });
+    }
+
+    #[test]
+    fn resolve_multiname_finds_qualified_property_in_given_namespace() {
+        let mut data = ScriptObjectData::base_new(None);
+        data.install_dynamic_property(qname("x"), Value::Undefined)
+            .unwrap();
+
+        assert_eq!(
+            data.resolve_multiname("x", &[Namespace::public()]),
+            Some(qname("x"))
+        );
+        assert_eq!(data.resolve_multiname("y", &[Namespace::public()]), None);
+    }
+
+    #[test]
+    fn resolve_multiname_any_namespace_matches_by_local_name_only() {
+        let mut data = ScriptObjectData::base_new(None);
+        data.install_dynamic_property(qname("x"), Value::Undefined)
+            .unwrap();
+
+        assert_eq!(
+            data.resolve_multiname("x", &[Namespace::any()]),
+            Some(qname("x"))
+        );
+    }
 }